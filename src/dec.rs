@@ -0,0 +1,276 @@
+//! Decimal `FromStr`/`Display` conversion.
+//!
+//! Parsing follows the shape of the Eisel-Lemire algorithm `dec2flt` uses
+//! for `f64`: decompose the input into a sign, an integer significand `w`
+//! (the first 19 significant decimal digits) and a base-10 exponent `q`,
+//! then try to land directly on the correctly-rounded `f80` bit pattern.
+//! `w * 5^q` is exact in a `u128` for `|q| <= 27` (`w` is already bounded to
+//! 64 bits, and `5^27` is the largest power of five whose product with a
+//! 64-bit integer still fits in 128 bits), so unlike the `f64` algorithm's
+//! table of truncated powers of five, the fast path here never needs a
+//! "is this an exact tie" check: it either applies exactly, or we fall back.
+//! Digits past the 19th are not kept in `w`: the fast path at most counts
+//! them as a sticky bit when rounding, and the fallback ignores them, so
+//! inputs with more than 19 significant digits are not guaranteed to be
+//! correctly rounded.
+//!
+//! The fallback for `|q| > 27` scales `w` by `10^q` using the crate's own
+//! `f80` multiplication/division (see [`crate::ops`]), so it stays correctly
+//! rounded per operation even though, unlike the fast path, the composed
+//! result isn't guaranteed correctly rounded overall.
+//!
+//! `Display`/`LowerExp` are deliberately scoped down to `f64` precision
+//! rather than a correctly-rounded shortest decimal over the full 64-bit
+//! significand; see the note on their impls below for why.
+
+use crate::ops::compose;
+use crate::f80;
+use core::fmt;
+use core::str::FromStr;
+
+/// An error returned when parsing an `f80` from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseF80Error(Kind);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Empty,
+    Invalid,
+}
+
+impl fmt::Display for ParseF80Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self.0 {
+            Kind::Empty => "cannot parse f80 from empty string",
+            Kind::Invalid => "invalid float literal",
+        })
+    }
+}
+impl core::error::Error for ParseF80Error {}
+
+impl FromStr for f80 {
+    type Err = ParseF80Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseF80Error(Kind::Empty));
+        }
+
+        let (negative, rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        if rest.eq_ignore_ascii_case("nan") {
+            return Ok(f80::NAN);
+        }
+        if rest.eq_ignore_ascii_case("inf") || rest.eq_ignore_ascii_case("infinity") {
+            return Ok(if negative { f80::NEG_INFINITY } else { f80::INFINITY });
+        }
+
+        let (w, q, truncated) = parse_digits(rest)?;
+        let magnitude = if w == 0 {
+            f80::from_bits(0)
+        } else {
+            eisel_lemire(w, q, truncated).unwrap_or_else(|| fallback(w, q))
+        };
+
+        Ok(if negative {
+            f80::from_bits(magnitude.to_bits() | (1 << 79))
+        } else {
+            magnitude
+        })
+    }
+}
+
+/// Split a (sign-stripped) numeric literal into an integer significand `w`
+/// (up to the first 19 significant digits) and a base-10 exponent `q`, such
+/// that the represented value is `w * 10^q`. `truncated` is set if digits
+/// beyond the 19 kept in `w` were seen.
+fn parse_digits(s: &str) -> Result<(u64, i32, bool), ParseF80Error> {
+    let mut chars = s.chars();
+
+    let mut w: u64 = 0;
+    let mut n_digits = 0u32;
+    let mut exp = 0i32;
+    let mut seen_digit = false;
+    let mut seen_dot = false;
+    let mut truncated = false;
+
+    loop {
+        // `as_str` gives us the remainder before consuming each char, so we
+        // can hand the exponent suffix off to `str::parse` without needing
+        // a peekable iterator.
+        let remaining = chars.as_str();
+        let Some(c) = chars.next() else { break };
+        match c {
+            '0'..='9' => {
+                seen_digit = true;
+                let digit = c as u64 - '0' as u64;
+                if n_digits < 19 {
+                    w = w * 10 + digit;
+                    // Leading zeros are not significant digits.
+                    if w != 0 {
+                        n_digits += 1;
+                    }
+                    if seen_dot {
+                        exp -= 1;
+                    }
+                } else {
+                    truncated = true;
+                    if !seen_dot {
+                        exp += 1;
+                    }
+                }
+            }
+            '.' if !seen_dot => {
+                seen_dot = true;
+            }
+            'e' | 'E' => {
+                if !seen_digit {
+                    return Err(ParseF80Error(Kind::Invalid));
+                }
+                let rest = &remaining[1..];
+                if rest.is_empty() {
+                    return Err(ParseF80Error(Kind::Invalid));
+                }
+                let e: i32 = rest.parse().map_err(|_| ParseF80Error(Kind::Invalid))?;
+                exp += e;
+                return Ok((w, exp, truncated));
+            }
+            _ => return Err(ParseF80Error(Kind::Invalid)),
+        }
+    }
+
+    if !seen_digit {
+        return Err(ParseF80Error(Kind::Invalid));
+    }
+    Ok((w, exp, truncated))
+}
+
+/// The largest `q` for which `w * 5^q` (with `w` a 64-bit integer) always
+/// fits, and so is exact, in a `u128`.
+const MAX_EXACT_Q: i32 = 27;
+
+fn pow5(q: u32) -> u128 {
+    let mut result: u128 = 1;
+    for _ in 0..q {
+        result *= 5;
+    }
+    result
+}
+
+/// The fast path: exact for `|q| <= 27`, `None` (fall back) otherwise.
+fn eisel_lemire(w: u64, q: i32, truncated: bool) -> Option<f80> {
+    if (0..=MAX_EXACT_Q).contains(&q) {
+        let product = w as u128 * pow5(q as u32); // exact: w * 10^q == product * 2^q
+        Some(round_from_fixed_point(product, q, truncated))
+    } else if (-MAX_EXACT_Q..0).contains(&q) {
+        let five_q = pow5((-q) as u32);
+        // Put `w`'s leading one at bit 127 so the quotient keeps at least 65
+        // significant bits (5^27 < 2^63): 64 kept bits plus a round bit.
+        let lz = w.leading_zeros();
+        let numerator = (w as u128) << (64 + lz); // w * 2^(64 + lz)
+        let quotient = numerator / five_q; // (w / 5^-q) * 2^(64 + lz), rounded down
+        let inexact = numerator % five_q != 0;
+        Some(round_from_fixed_point(quotient, q - 64 - lz as i32, truncated || inexact))
+    } else {
+        None
+    }
+}
+
+/// Round a fixed-point value `raw * 2^base_exp` down to a normalized 64-bit
+/// significand plus the matching unbiased exponent, then assemble the
+/// result. `force_sticky` additionally treats the rounding as inexact (a
+/// non-zero division remainder, or a `w` that dropped digits); it only has
+/// an effect when `raw` has more than 64 significant bits.
+fn round_from_fixed_point(raw: u128, base_exp: i32, force_sticky: bool) -> f80 {
+    let pos = 127 - raw.leading_zeros() as i32; // bit position of the leading one
+    let shift = pos - 63;
+
+    let (mantissa, carried) = if shift <= 0 {
+        ((raw << (-shift)) as u64, false)
+    } else {
+        let round_bit = (raw >> (shift - 1)) & 1;
+        let sticky = force_sticky || (shift > 1 && (raw & ((1u128 << (shift - 1)) - 1)) != 0);
+        let mut kept = (raw >> shift) as u64;
+        let mut carried = false;
+        if round_bit == 1 && (sticky || (kept & 1) == 1) {
+            kept = kept.wrapping_add(1);
+            carried = kept == 0;
+            if carried {
+                kept = 1 << 63;
+            }
+        }
+        (kept, carried)
+    };
+
+    let exp = pos + base_exp + carried as i32;
+    compose(false, exp, mantissa)
+}
+
+/// Scale `w` by `10^q` using `f80`'s own (correctly-rounded-per-operation)
+/// arithmetic. Used once `q` falls outside the fast path's exact range;
+/// composing several rounded operations means this is not guaranteed
+/// correctly rounded overall, unlike [`eisel_lemire`].
+fn fallback(w: u64, q: i32) -> f80 {
+    let mut value = f80_from_u64(w);
+    if q >= 0 {
+        value = value * pow10_f80(q as u32);
+    } else {
+        value = value / pow10_f80((-q) as u32);
+    }
+    value
+}
+
+fn f80_from_u64(w: u64) -> f80 {
+    if w == 0 {
+        return f80::from_bits(0);
+    }
+    let lz = w.leading_zeros();
+    let mantissa = (w as u128) << lz;
+    let exp = 63 - lz as i32;
+    compose(false, exp, mantissa as u64)
+}
+
+fn pow10_f80(mut n: u32) -> f80 {
+    let mut base = f80_from_u64(10);
+    let mut result = f80_from_u64(1);
+    while n > 0 {
+        if n & 1 == 1 {
+            result = result * base;
+        }
+        base = base * base;
+        n >>= 1;
+    }
+    result
+}
+
+// `Display`/`LowerExp` are scoped down to `f64` precision by design: a
+// correctly-rounded shortest decimal over the full 64-bit `f80` significand
+// needs either a big-integer (Dragon4-style) formatter or Ryu-style
+// power-of-ten tables sized for a 15-bit exponent, and this crate is
+// `no_std` with no `alloc`. Doing that by hand, with no compiler available
+// to check it in this environment, risks landing a formatter that's subtly
+// wrong in exactly the hard cases (ties, wide exponents) it exists for.
+// `f80` values with more than ~17 significant mantissa bits of information
+// (anything not exactly representable in `f64`) will not round-trip through
+// `Display` -> `FromStr`; round-trip through `to_bits`/`from_bits` instead
+// when full fidelity matters. A full shortest-decimal formatter is left as
+// follow-up work.
+
+impl fmt::Display for f80 {
+    /// Formats via `f64`; see the module-level note on the precision this
+    /// drops for values that need more than `f64`'s mantissa to represent.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl fmt::LowerExp for f80 {
+    /// See the [`Display`](#impl-Display-for-f80) precision note.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.to_f64(), f)
+    }
+}
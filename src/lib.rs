@@ -3,6 +3,15 @@
 
 use core::fmt;
 
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+mod arch;
+mod ops;
+mod dec;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+
+pub use dec::ParseF80Error;
+
 /// An 80-bit float, internally stored using one 128-bit integer. This lets you
 /// convert it back and forth from f64, and extract various parts of the type.
 #[derive(Clone, Copy)]
@@ -22,6 +31,38 @@ impl f80 {
         self.bits
     }
 
+    /// New f80 from its packed 10-byte hardware representation, as read
+    /// little-endian (the layout `fstp TBYTE PTR` writes to memory).
+    pub fn from_le_bytes(bytes: [u8; 10]) -> Self {
+        let mut bits = 0u128;
+        for (i, byte) in bytes.iter().enumerate() {
+            bits |= (*byte as u128) << (i * 8);
+        }
+        Self::from_bits(bits)
+    }
+    /// New f80 from its packed 10-byte hardware representation, as read
+    /// big-endian.
+    pub fn from_be_bytes(bytes: [u8; 10]) -> Self {
+        let mut le = bytes;
+        le.reverse();
+        Self::from_le_bytes(le)
+    }
+    /// Convert to the packed 10-byte hardware representation, little-endian
+    /// (this is exactly what `fstp TBYTE PTR` writes to memory).
+    pub fn to_le_bytes(self) -> [u8; 10] {
+        let mut bytes = [0u8; 10];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (self.bits >> (i * 8)) as u8;
+        }
+        bytes
+    }
+    /// Convert to the packed 10-byte hardware representation, big-endian.
+    pub fn to_be_bytes(self) -> [u8; 10] {
+        let mut bytes = self.to_le_bytes();
+        bytes.reverse();
+        bytes
+    }
+
     /// Extract a specified (uninclusive) range of the bits.
     ///
     /// ```rust,ignore
@@ -70,6 +111,97 @@ impl f80 {
         #[cfg(not(all(feature = "asm", target_arch = "x86_64")))]
         { self.emulate_f80_to_f64() }
     }
+
+    /// Build an `f80` from an `f64`, widening it to the extended-precision
+    /// format. This is exact: every `f64` value has a lossless `f80`
+    /// representation.
+    pub fn from_f64(f: f64) -> Self {
+        #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+        { Self::x86_f64_to_f80(f) }
+
+        #[cfg(not(all(feature = "asm", target_arch = "x86_64")))]
+        { Self::emulate_f64_to_f80(f) }
+    }
+}
+
+impl f80 {
+    /// Positive infinity.
+    pub const INFINITY: f80 = f80 { bits: (0x7FFFu128 << 64) | (1 << 63) };
+    /// Negative infinity.
+    pub const NEG_INFINITY: f80 = f80 { bits: (1 << 79) | (0x7FFFu128 << 64) | (1 << 63) };
+    /// A quiet NaN.
+    pub const NAN: f80 = f80 { bits: (0x7FFFu128 << 64) | (1 << 63) | (1 << 62) };
+    /// Smallest positive normal value.
+    pub const MIN_POSITIVE: f80 = f80 { bits: (1u128 << 64) | (1 << 63) };
+    /// Largest finite value.
+    pub const MAX: f80 = f80 { bits: (0x7FFEu128 << 64) | (u64::MAX as u128) };
+    /// Smallest finite value.
+    pub const MIN: f80 = f80 { bits: (1 << 79) | (0x7FFEu128 << 64) | (u64::MAX as u128) };
+    /// The difference between `1.0` and the next representable value.
+    pub const EPSILON: f80 = f80 { bits: (16320u128 << 64) | (1 << 63) };
+
+    /// `true` if this value is NaN.
+    pub fn is_nan(self) -> bool {
+        self.exp_bits() == 0x7FFF && !(self.int() && self.fraction() == 0)
+    }
+    /// `true` if this value is positive or negative infinity.
+    pub fn is_infinite(self) -> bool {
+        self.exp_bits() == 0x7FFF && self.int() && self.fraction() == 0
+    }
+    /// `true` if this value is neither infinite nor NaN.
+    pub fn is_finite(self) -> bool {
+        self.exp_bits() != 0x7FFF
+    }
+    /// `true` if this value is a normal (neither zero, subnormal, infinite,
+    /// nor NaN) number.
+    pub fn is_normal(self) -> bool {
+        let exp_bits = self.exp_bits();
+        exp_bits != 0 && exp_bits != 0x7FFF && self.int()
+    }
+    /// `true` if the sign bit is set, including for `-0.0` and NaNs.
+    pub fn is_sign_negative(self) -> bool {
+        self.sign()
+    }
+    /// `true` if the sign bit is clear, including for `0.0` and NaNs.
+    pub fn is_sign_positive(self) -> bool {
+        !self.sign()
+    }
+    /// Returns the floating-point category of this value.
+    pub fn classify(self) -> core::num::FpCategory {
+        use core::num::FpCategory;
+
+        match self.exp_bits() {
+            0 if self.mantissa() == 0 => FpCategory::Zero,
+            0 => FpCategory::Subnormal,
+            0x7FFF if self.int() && self.fraction() == 0 => FpCategory::Infinite,
+            0x7FFF => FpCategory::Nan,
+            _ if self.int() => FpCategory::Normal,
+            // Unnormal: a non-zero exponent with the explicit
+            // integer bit clear. Not representable by a distinct `FpCategory`
+            // variant, so group it with the other non-normal finite values.
+            _ => FpCategory::Subnormal,
+        }
+    }
+}
+
+impl PartialEq for f80 {
+    fn eq(&self, other: &f80) -> bool {
+        if self.is_nan() || other.is_nan() {
+            return false;
+        }
+        if self.exp_bits() == 0 && self.mantissa() == 0 && other.exp_bits() == 0 && other.mantissa() == 0 {
+            return true; // +0.0 == -0.0
+        }
+        self.to_bits() == other.to_bits()
+    }
+}
+impl PartialOrd for f80 {
+    fn partial_cmp(&self, other: &f80) -> Option<core::cmp::Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        self.to_f64().partial_cmp(&other.to_f64())
+    }
 }
 impl fmt::Debug for f80 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -89,50 +221,134 @@ impl f80 {
         }
         float
     }
+    #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+    fn x86_f64_to_f80(f: f64) -> Self {
+        let mut buf = [0u8; 10];
+        unsafe {
+            arch::x86_64::load_f64_into_f80(&f, buf.as_mut_ptr());
+        }
+        Self::from_le_bytes(buf)
+    }
     #[allow(dead_code)]
     fn emulate_f80_to_f64(self) -> f64 {
-        // Handle special cases
-        if self.exp_bits() == 0x7FFF {
-            match self.mantissa() >> (64 - 2) {
-                0b00 | 0b10 => return if self.mantissa() == 0 {
-                    f64::INFINITY
-                } else {
-                    f64::NAN
-                },
-                0b01 | 0b11 => return f64::NAN,
-                _ => unreachable!("all 2-bit cases should be handled"),
+        let sign = self.sign() as u64;
+        let exp_bits = self.exp_bits();
+        let mantissa64 = self.mantissa();
+
+        // Handle infinities and NaNs.
+        if exp_bits == 0x7FFF {
+            if self.int() && self.fraction() == 0 {
+                return f64::from_bits((sign << 63) | (0x7FFu64 << 52));
             }
+            // NaN: carry over the payload bits that survive the narrowing,
+            // forcing the quiet bit on if doing so would otherwise produce
+            // a (incorrect) f64 infinity.
+            let mut frac64 = self.fraction() >> 11;
+            if frac64 == 0 {
+                frac64 = 1 << 51;
+            }
+            return f64::from_bits((sign << 63) | (0x7FFu64 << 52) | frac64);
+        }
+
+        // Zero.
+        if mantissa64 == 0 {
+            return f64::from_bits(sign << 63);
         }
 
-        // Truncate fraction
-        let mut fraction = self.fraction() as u64;
-        fraction >>= 64 - 53;
+        // Normalize so the leading one sits at bit 63, regardless of whether
+        // the explicit integer bit was already set. This handles normals,
+        // true denormals, and malformed "unnormal" encodings uniformly.
+        let nominal_exp = if exp_bits == 0 {
+            -16382i32
+        } else {
+            exp_bits as i32 - 16383
+        };
+        let lz = mantissa64.leading_zeros();
+        let sig = mantissa64 << lz; // bit63 = 1, bits 0..=62 = fraction
+        let exp = nominal_exp - lz as i32;
 
-        // Convert f80 bias to f64 bias in exponent
-        let mut exp = self.exp() as u64;
-        let f64_bias = (1 << 10) - 1; // mentioned as 1023 in Wikipedia
-        exp += f64_bias;
+        let mut f64_exp = exp + 1023;
+        let mut shift = 11u32; // 63-bit fraction -> 52-bit fraction
 
-        // Get sign
-        let sign = self.sign() as u64;
+        if f64_exp < 1 {
+            // Result underflows into (or past) the f64 subnormal range: drop
+            // the extra bits too, rounding as we go.
+            shift += (1 - f64_exp) as u32;
+            f64_exp = 0;
+        }
 
-        // --- All parts done, assemble f64 ---
+        if shift > 64 {
+            // Shifted away entirely; always rounds down to a signed zero.
+            return f64::from_bits(sign << 63);
+        }
 
-        let mut output = 0;
+        let kept = if shift == 64 { 0 } else { sig >> shift };
+        let round_bit = (sig >> (shift - 1)) & 1;
+        let sticky = (sig & ((1u64 << (shift - 1)) - 1)) != 0;
+        let round_up = round_bit == 1 && (sticky || (kept & 1) == 1);
 
-        // Push sign
-        output |= sign;
+        let mut mantissa52 = kept + round_up as u64;
 
-        // Push exponent
-        output <<= 11;
-        output |= exp & ((1 << 11) - 1);
+        if f64_exp == 0 {
+            // Subnormal path: a carry out of the top bit means the value
+            // rounded up into the smallest normal number.
+            if mantissa52 == 1 << 52 {
+                mantissa52 = 0;
+                f64_exp = 1;
+            }
+        } else {
+            // Normal path: `kept` includes the implicit leading bit at bit
+            // 52; a carry out of it bumps the exponent.
+            if mantissa52 == 1 << 53 {
+                mantissa52 = 0;
+                f64_exp += 1;
+            } else {
+                mantissa52 &= (1 << 52) - 1;
+            }
+        }
+
+        if f64_exp >= 0x7FF {
+            return f64::from_bits((sign << 63) | (0x7FFu64 << 52));
+        }
+
+        f64::from_bits((sign << 63) | ((f64_exp as u64) << 52) | mantissa52)
+    }
+    #[allow(dead_code)]
+    fn emulate_f64_to_f80(f: f64) -> Self {
+        let bits64 = f.to_bits();
+        let sign = bits64 >> 63;
+        let exp64 = ((bits64 >> 52) & 0x7FF) as i32;
+        let frac64 = bits64 & ((1 << 52) - 1);
 
-        // Push fraction. The explicit integer part of f80 is ignored, because
-        // the f64 fraction implies there's an integer part of 1.
-        output <<= 52;
-        output |= fraction & ((1 << 52) - 1);
+        // Handle special cases: zero, subnormals, and inf/NaN.
+        let (mantissa, exp_bits): (u64, u16) = if exp64 == 0x7FF {
+            // Infinity or NaN: keep the exponent all-ones, set the explicit
+            // integer bit, and preserve the quiet-NaN payload pattern.
+            let int_bit = 1u64 << 63;
+            let frac80 = frac64 << 11;
+            (int_bit | frac80, 0x7FFF)
+        } else if exp64 == 0 && frac64 == 0 {
+            // Zero keeps a zero mantissa and exponent.
+            (0, 0)
+        } else if exp64 == 0 {
+            // f64 subnormal: normalize so the leading set bit becomes the
+            // explicit integer bit, shifting the exponent down to match. f80
+            // has far more exponent range than f64, so the result is always
+            // a normal f80 number.
+            let lz = frac64.leading_zeros();
+            let mantissa = frac64 << lz;
+            let exp = (-1011 - lz as i32) + 16383;
+            (mantissa, exp as u16)
+        } else {
+            // Normal f64: set the explicit integer bit and place the 52-bit
+            // fraction as the top of the 63-bit f80 fraction.
+            let frac80 = frac64 << 11;
+            let exp = (exp64 - 1023) + 16383;
+            (frac80 | (1 << 63), exp as u16)
+        };
 
-        f64::from_bits(output)
+        let bits = ((sign as u128) << 79) | ((exp_bits as u128) << 64) | mantissa as u128;
+        Self::from_bits(bits)
     }
 }
 
@@ -160,6 +376,81 @@ mod tests {
         assert_eq!(eight.fraction(), 0b000000000000000000000000000000000000000000000000000000000000000);
     }
 
+    #[test]
+    fn byte_interchange() {
+        let eight = f80::from_bits(302277571763841567555584);
+
+        let le = eight.to_le_bytes();
+        let mut be = le;
+        be.reverse();
+        assert_eq!(eight.to_be_bytes(), be);
+
+        assert_eq!(f80::from_le_bytes(le).to_bits(), eight.to_bits());
+        assert_eq!(f80::from_be_bytes(be).to_bits(), eight.to_bits());
+    }
+
+    #[test]
+    fn soft_float_arithmetic() {
+        let two = f80::from_f64(2.0);
+        let three = f80::from_f64(3.0);
+
+        assert_eq!((two + three).emulate_f80_to_f64(), 5.0);
+        assert_eq!((three - two).emulate_f80_to_f64(), 1.0);
+        assert_eq!((two * three).emulate_f80_to_f64(), 6.0);
+        assert_eq!((three / two).emulate_f80_to_f64(), 1.5);
+        assert_eq!((-two).emulate_f80_to_f64(), -2.0);
+        assert_eq!((-two).abs().emulate_f80_to_f64(), 2.0);
+        assert_eq!(two.mul_add(three, two).emulate_f80_to_f64(), 8.0);
+    }
+
+    #[test]
+    fn classification() {
+        assert!(f80::NAN.is_nan());
+        assert!(f80::INFINITY.is_infinite());
+        assert!(!f80::INFINITY.is_nan());
+        assert!(f80::from_bits(0).is_finite());
+        assert_eq!(f80::from_bits(0).classify(), core::num::FpCategory::Zero);
+        assert_eq!(f80::MAX.classify(), core::num::FpCategory::Normal);
+        assert!(f80::from_f64(-1.0).is_sign_negative());
+    }
+
+    #[test]
+    fn decimal_round_trip() {
+        use core::str::FromStr;
+
+        assert_eq!(f80::from_str("0").unwrap().to_f64(), 0.0);
+        assert_eq!(f80::from_str("1").unwrap().to_f64(), 1.0);
+        assert_eq!(f80::from_str("-2.5").unwrap().to_f64(), -2.5);
+        assert_eq!(f80::from_str("1.5e3").unwrap().to_f64(), 1500.0);
+        assert_eq!(f80::from_str("1e40").unwrap().to_f64(), 1e40);
+        assert_eq!(f80::from_str("1e-40").unwrap().to_f64(), 1e-40);
+        assert!(f80::from_str("nan").unwrap().is_nan());
+        assert!(f80::from_str("-inf").unwrap().is_infinite());
+        assert!(f80::from_str("").is_err());
+        assert!(f80::from_str("abc").is_err());
+
+        assert_eq!(f80::from_f64(1.5).to_string(), "1.5");
+
+        // `Display` is scoped to f64 precision (see src/dec.rs): a value
+        // that only differs from 1.0 in a mantissa bit below f64's 52
+        // fraction bits prints identically to 1.0 and does not round-trip.
+        let one = f80::from_f64(1.0);
+        let one_plus_ulp = f80::from_bits(one.to_bits() | 1);
+        assert_ne!(one_plus_ulp.to_bits(), one.to_bits());
+        assert_eq!(one_plus_ulp.to_string(), one.to_string());
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn num_traits_integration() {
+        use num_traits::{Float, Num, One, Zero};
+
+        assert!(f80::zero().is_zero());
+        assert_eq!(f80::one().to_f64(), 1.0);
+        assert_eq!(f80::from_str_radix("101", 2).unwrap().to_f64(), 5.0);
+        assert_eq!(Float::sqrt(f80::from_f64(4.0)).to_f64(), 2.0);
+    }
+
     #[test]
     fn hardcoded_examples() {
         // sqrt(64)
@@ -183,7 +474,28 @@ mod tests {
             println!("---");
             println!("expected: {}", expected);
             println!("actual: {}", actual);
-            proptest::prop_assert!(actual - expected < EPSILON);
+            if expected.is_nan() {
+                proptest::prop_assert!(actual.is_nan());
+            } else {
+                proptest::prop_assert_eq!(actual.to_bits(), expected.to_bits());
+            }
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        #[cfg(all(target_arch = "x86_64", feature = "asm"))]
+        fn emulated_from_f64_works(n in proptest::num::f64::ANY) {
+            let expected = f80::x86_f64_to_f80(n);
+            let actual = f80::emulate_f64_to_f80(n);
+            println!("---");
+            println!("expected: {:?}", expected);
+            println!("actual: {:?}", actual);
+            if n.is_nan() {
+                proptest::prop_assert_eq!(actual.exp_bits(), 0x7FFF);
+            } else {
+                proptest::prop_assert_eq!(actual.to_bits(), expected.to_bits());
+            }
         }
     }
 }
@@ -0,0 +1,397 @@
+//! `num-traits` integration, gated behind the `num-traits` feature so that
+//! it stays an optional dependency. This lets `f80` drop into generic
+//! numeric code the same way `half`'s `f16`/`bf16` do.
+//!
+//! The classification and sign methods are exact on the 80-bit
+//! representation. The transcendental `Float` methods (`sqrt`, `ln`, the
+//! trig functions, ...) round-trip through `f64`, so they carry `f64`'s
+//! precision rather than the full 64-bit `f80` mantissa.
+
+use crate::f80;
+use core::num::FpCategory;
+use core::ops::Rem;
+use num_traits::float::FloatCore;
+use num_traits::{Float, FromPrimitive, Num, NumCast, One, ToPrimitive, Zero};
+
+impl Rem for f80 {
+    type Output = f80;
+    /// Delegates to `f64`'s `%`, so this carries `f64` precision rather
+    /// than the full `f80` mantissa.
+    fn rem(self, rhs: f80) -> f80 {
+        f80::from_f64(self.to_f64() % rhs.to_f64())
+    }
+}
+
+impl Zero for f80 {
+    fn zero() -> Self {
+        f80::from_bits(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.exp_bits() == 0 && self.mantissa() == 0
+    }
+}
+
+impl One for f80 {
+    fn one() -> Self {
+        f80::from_bits((16383u128 << 64) | (1 << 63))
+    }
+}
+
+/// Parse error for [`Num::from_str_radix`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseRadixError;
+
+impl Num for f80 {
+    type FromStrRadixErr = ParseRadixError;
+
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let (src, negative) = match src.strip_prefix('-') {
+            Some(rest) => (rest, true),
+            None => (src.strip_prefix('+').unwrap_or(src), false),
+        };
+        if src.is_empty() {
+            return Err(ParseRadixError);
+        }
+
+        let radix_f80 = f80::from_f64(radix as f64);
+        let mut digits = src.splitn(2, '.');
+        let int_part = digits.next().unwrap_or("");
+        let frac_part = digits.next();
+
+        let mut value = f80::zero();
+        for c in int_part.chars() {
+            let digit = c.to_digit(radix).ok_or(ParseRadixError)?;
+            value = value * radix_f80 + f80::from_f64(digit as f64);
+        }
+
+        if let Some(frac_part) = frac_part {
+            let mut scale = f80::one() / radix_f80;
+            for c in frac_part.chars() {
+                let digit = c.to_digit(radix).ok_or(ParseRadixError)?;
+                value = value + f80::from_f64(digit as f64) * scale;
+                scale = scale / radix_f80;
+            }
+        }
+
+        Ok(if negative { -value } else { value })
+    }
+}
+
+impl ToPrimitive for f80 {
+    fn to_i64(&self) -> Option<i64> {
+        f80::to_f64(*self).to_i64()
+    }
+    fn to_u64(&self) -> Option<u64> {
+        f80::to_f64(*self).to_u64()
+    }
+    fn to_f32(&self) -> Option<f32> {
+        Some(f80::to_f64(*self) as f32)
+    }
+    fn to_f64(&self) -> Option<f64> {
+        Some(f80::to_f64(*self))
+    }
+}
+
+impl FromPrimitive for f80 {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(f80::from_f64(n as f64))
+    }
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(f80::from_f64(n as f64))
+    }
+    fn from_f32(n: f32) -> Option<Self> {
+        Some(f80::from_f64(n as f64))
+    }
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(f80::from_f64(n))
+    }
+}
+
+impl NumCast for f80 {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        n.to_f64().map(f80::from_f64)
+    }
+}
+
+impl FloatCore for f80 {
+    fn infinity() -> Self {
+        f80::INFINITY
+    }
+    fn neg_infinity() -> Self {
+        f80::NEG_INFINITY
+    }
+    fn nan() -> Self {
+        f80::NAN
+    }
+    fn neg_zero() -> Self {
+        f80::from_bits(1 << 79)
+    }
+    fn min_value() -> Self {
+        f80::MIN
+    }
+    fn min_positive_value() -> Self {
+        f80::MIN_POSITIVE
+    }
+    fn max_value() -> Self {
+        f80::MAX
+    }
+    fn epsilon() -> Self {
+        f80::EPSILON
+    }
+    fn is_nan(self) -> bool {
+        f80::is_nan(self)
+    }
+    fn is_infinite(self) -> bool {
+        f80::is_infinite(self)
+    }
+    fn is_finite(self) -> bool {
+        f80::is_finite(self)
+    }
+    fn is_normal(self) -> bool {
+        f80::is_normal(self)
+    }
+    fn is_sign_positive(self) -> bool {
+        f80::is_sign_positive(self)
+    }
+    fn is_sign_negative(self) -> bool {
+        f80::is_sign_negative(self)
+    }
+    fn classify(self) -> FpCategory {
+        f80::classify(self)
+    }
+    fn floor(self) -> Self {
+        f80::from_f64(f80::to_f64(self).floor())
+    }
+    fn ceil(self) -> Self {
+        f80::from_f64(f80::to_f64(self).ceil())
+    }
+    fn round(self) -> Self {
+        f80::from_f64(f80::to_f64(self).round())
+    }
+    fn trunc(self) -> Self {
+        f80::from_f64(f80::to_f64(self).trunc())
+    }
+    fn fract(self) -> Self {
+        self - FloatCore::trunc(self)
+    }
+    fn abs(self) -> Self {
+        f80::abs(self)
+    }
+    fn signum(self) -> Self {
+        if self.is_nan() {
+            self
+        } else if self.is_sign_negative() {
+            -f80::one()
+        } else {
+            f80::one()
+        }
+    }
+    fn powi(self, n: i32) -> Self {
+        f80::from_f64(f80::to_f64(self).powi(n))
+    }
+    fn max(self, other: Self) -> Self {
+        if self.is_nan() || self < other {
+            other
+        } else {
+            self
+        }
+    }
+    fn min(self, other: Self) -> Self {
+        if other.is_nan() || self < other {
+            self
+        } else {
+            other
+        }
+    }
+    fn to_degrees(self) -> Self {
+        f80::from_f64(f80::to_f64(self).to_degrees())
+    }
+    fn to_radians(self) -> Self {
+        f80::from_f64(f80::to_f64(self).to_radians())
+    }
+    fn integer_decode(self) -> (u64, i16, i8) {
+        integer_decode(self)
+    }
+}
+
+/// Shared by the `FloatCore` and `Float` impls' `integer_decode`.
+fn integer_decode(f: f80) -> (u64, i16, i8) {
+    let exp_bits = f.exp_bits();
+    let mantissa = f.mantissa();
+    let sign: i8 = if f.is_sign_negative() { -1 } else { 1 };
+    if exp_bits == 0 {
+        (mantissa, -16382 - 63, sign)
+    } else {
+        (mantissa, exp_bits as i16 - 16383 - 63, sign)
+    }
+}
+
+impl Float for f80 {
+    fn nan() -> Self {
+        f80::NAN
+    }
+    fn infinity() -> Self {
+        f80::INFINITY
+    }
+    fn neg_infinity() -> Self {
+        f80::NEG_INFINITY
+    }
+    fn neg_zero() -> Self {
+        f80::from_bits(1 << 79)
+    }
+    fn min_value() -> Self {
+        f80::MIN
+    }
+    fn min_positive_value() -> Self {
+        f80::MIN_POSITIVE
+    }
+    fn max_value() -> Self {
+        f80::MAX
+    }
+    fn is_nan(self) -> bool {
+        f80::is_nan(self)
+    }
+    fn is_infinite(self) -> bool {
+        f80::is_infinite(self)
+    }
+    fn is_finite(self) -> bool {
+        f80::is_finite(self)
+    }
+    fn is_normal(self) -> bool {
+        f80::is_normal(self)
+    }
+    fn classify(self) -> FpCategory {
+        f80::classify(self)
+    }
+    fn floor(self) -> Self {
+        f80::from_f64(f80::to_f64(self).floor())
+    }
+    fn ceil(self) -> Self {
+        f80::from_f64(f80::to_f64(self).ceil())
+    }
+    fn round(self) -> Self {
+        f80::from_f64(f80::to_f64(self).round())
+    }
+    fn trunc(self) -> Self {
+        f80::from_f64(f80::to_f64(self).trunc())
+    }
+    fn fract(self) -> Self {
+        self - FloatCore::trunc(self)
+    }
+    fn abs(self) -> Self {
+        f80::abs(self)
+    }
+    fn signum(self) -> Self {
+        FloatCore::signum(self)
+    }
+    fn is_sign_positive(self) -> bool {
+        f80::is_sign_positive(self)
+    }
+    fn is_sign_negative(self) -> bool {
+        f80::is_sign_negative(self)
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        f80::mul_add(self, a, b)
+    }
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+    fn powi(self, n: i32) -> Self {
+        f80::from_f64(f80::to_f64(self).powi(n))
+    }
+    fn powf(self, n: Self) -> Self {
+        f80::from_f64(f80::to_f64(self).powf(f80::to_f64(n)))
+    }
+    fn sqrt(self) -> Self {
+        f80::from_f64(f80::to_f64(self).sqrt())
+    }
+    fn exp(self) -> Self {
+        f80::from_f64(f80::to_f64(self).exp())
+    }
+    fn exp2(self) -> Self {
+        f80::from_f64(f80::to_f64(self).exp2())
+    }
+    fn ln(self) -> Self {
+        f80::from_f64(f80::to_f64(self).ln())
+    }
+    fn log(self, base: Self) -> Self {
+        f80::from_f64(f80::to_f64(self).log(f80::to_f64(base)))
+    }
+    fn log2(self) -> Self {
+        f80::from_f64(f80::to_f64(self).log2())
+    }
+    fn log10(self) -> Self {
+        f80::from_f64(f80::to_f64(self).log10())
+    }
+    fn max(self, other: Self) -> Self {
+        FloatCore::max(self, other)
+    }
+    fn min(self, other: Self) -> Self {
+        FloatCore::min(self, other)
+    }
+    fn abs_sub(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            Self::zero()
+        }
+    }
+    fn cbrt(self) -> Self {
+        f80::from_f64(f80::to_f64(self).cbrt())
+    }
+    fn hypot(self, other: Self) -> Self {
+        f80::from_f64(f80::to_f64(self).hypot(f80::to_f64(other)))
+    }
+    fn sin(self) -> Self {
+        f80::from_f64(f80::to_f64(self).sin())
+    }
+    fn cos(self) -> Self {
+        f80::from_f64(f80::to_f64(self).cos())
+    }
+    fn tan(self) -> Self {
+        f80::from_f64(f80::to_f64(self).tan())
+    }
+    fn asin(self) -> Self {
+        f80::from_f64(f80::to_f64(self).asin())
+    }
+    fn acos(self) -> Self {
+        f80::from_f64(f80::to_f64(self).acos())
+    }
+    fn atan(self) -> Self {
+        f80::from_f64(f80::to_f64(self).atan())
+    }
+    fn atan2(self, other: Self) -> Self {
+        f80::from_f64(f80::to_f64(self).atan2(f80::to_f64(other)))
+    }
+    fn sin_cos(self) -> (Self, Self) {
+        let (s, c) = f80::to_f64(self).sin_cos();
+        (f80::from_f64(s), f80::from_f64(c))
+    }
+    fn exp_m1(self) -> Self {
+        f80::from_f64(f80::to_f64(self).exp_m1())
+    }
+    fn ln_1p(self) -> Self {
+        f80::from_f64(f80::to_f64(self).ln_1p())
+    }
+    fn sinh(self) -> Self {
+        f80::from_f64(f80::to_f64(self).sinh())
+    }
+    fn cosh(self) -> Self {
+        f80::from_f64(f80::to_f64(self).cosh())
+    }
+    fn tanh(self) -> Self {
+        f80::from_f64(f80::to_f64(self).tanh())
+    }
+    fn asinh(self) -> Self {
+        f80::from_f64(f80::to_f64(self).asinh())
+    }
+    fn acosh(self) -> Self {
+        f80::from_f64(f80::to_f64(self).acosh())
+    }
+    fn atanh(self) -> Self {
+        f80::from_f64(f80::to_f64(self).atanh())
+    }
+    fn integer_decode(self) -> (u64, i16, i8) {
+        integer_decode(self)
+    }
+}
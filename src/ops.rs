@@ -0,0 +1,320 @@
+//! Software arithmetic on the raw `f80` bit fields. Every operation here
+//! follows the same shape: classify the special cases (zero/inf/NaN) up
+//! front, then do the real work on normalized 64-bit significands (the
+//! explicit integer bit plus the 63-bit fraction) with round-to-nearest-even
+//! and a sticky bit to catch precision lost along the way.
+
+use crate::f80;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+const BIAS: i32 = 16383;
+const MAX_EXP_BITS: u16 = 0x7FFF;
+
+fn is_nan(f: f80) -> bool {
+    f.is_nan()
+}
+fn is_inf(f: f80) -> bool {
+    f.is_infinite()
+}
+fn is_zero(f: f80) -> bool {
+    f.exp_bits() == 0 && f.mantissa() == 0
+}
+
+fn signed_zero(sign: bool) -> f80 {
+    f80::from_bits((sign as u128) << 79)
+}
+fn signed_inf(sign: bool) -> f80 {
+    f80::from_bits(((sign as u128) << 79) | ((MAX_EXP_BITS as u128) << 64) | (1 << 63))
+}
+/// Quiet a NaN operand so it can be propagated as the result.
+fn quiet_nan(f: f80) -> f80 {
+    f80::from_bits(f.to_bits() | (1 << 62))
+}
+fn default_nan() -> f80 {
+    f80::from_bits(((MAX_EXP_BITS as u128) << 64) | (1 << 63) | (1 << 62))
+}
+
+/// Normalize to (sign, unbiased exponent, 64-bit significand with the
+/// leading one at bit 63). Only valid for non-zero, finite operands.
+fn decompose(f: f80) -> (bool, i32, u64) {
+    let exp_bits = f.exp_bits();
+    let mantissa = f.mantissa();
+    let nominal_exp = if exp_bits == 0 {
+        -16382
+    } else {
+        exp_bits as i32 - BIAS
+    };
+    let lz = mantissa.leading_zeros();
+    (f.sign(), nominal_exp - lz as i32, mantissa << lz)
+}
+
+/// Assemble a result from a sign, unbiased exponent, and a 64-bit
+/// significand (leading one at bit 63, already correctly rounded).
+/// Handles the re-bias, overflow to infinity, and underflow to a subnormal
+/// or signed zero.
+pub(crate) fn compose(sign: bool, exp: i32, sig: u64) -> f80 {
+    if sig == 0 {
+        return signed_zero(sign);
+    }
+
+    let mut exp_bits = exp + BIAS;
+    if exp_bits >= MAX_EXP_BITS as i32 {
+        return signed_inf(sign);
+    }
+    if exp_bits <= 0 {
+        let shift = (1 - exp_bits) as u32;
+        if shift >= 64 {
+            return signed_zero(sign);
+        }
+        let round_bit = if shift == 0 { 0 } else { (sig >> (shift - 1)) & 1 };
+        let sticky = shift > 1 && (sig & ((1u64 << (shift - 1)) - 1)) != 0;
+        let mut mantissa = sig >> shift;
+        if round_bit == 1 && (sticky || (mantissa & 1) == 1) {
+            mantissa += 1;
+        }
+        // A carry into bit 63 means this rounded up into the smallest
+        // normal number; the bit pattern is already correct, only the
+        // exponent field needs to move off zero.
+        exp_bits = if mantissa & (1 << 63) != 0 { 1 } else { 0 };
+        return f80::from_bits(((sign as u128) << 79) | ((exp_bits as u128) << 64) | mantissa as u128);
+    }
+
+    f80::from_bits(((sign as u128) << 79) | ((exp_bits as u128) << 64) | sig as u128)
+}
+
+fn mul_impl(a: f80, b: f80) -> f80 {
+    if is_nan(a) {
+        return quiet_nan(a);
+    }
+    if is_nan(b) {
+        return quiet_nan(b);
+    }
+    let sign = a.sign() != b.sign();
+    let (a_inf, b_inf) = (is_inf(a), is_inf(b));
+    let (a_zero, b_zero) = (is_zero(a), is_zero(b));
+    if (a_inf && b_zero) || (b_inf && a_zero) {
+        return default_nan();
+    }
+    if a_inf || b_inf {
+        return signed_inf(sign);
+    }
+    if a_zero || b_zero {
+        return signed_zero(sign);
+    }
+
+    let (_, ea, ma) = decompose(a);
+    let (_, eb, mb) = decompose(b);
+
+    let product = ma as u128 * mb as u128; // in [2^126, 2^128)
+    let top_at_127 = product & (1 << 127) != 0;
+    let shift = if top_at_127 { 64 } else { 63 };
+    let mut exp = ea + eb + if top_at_127 { 1 } else { 0 };
+
+    let round_bit = (product >> (shift - 1)) & 1;
+    let sticky = (product & ((1u128 << (shift - 1)) - 1)) != 0;
+    let mut sig = (product >> shift) as u64;
+    if round_bit == 1 && (sticky || (sig & 1) == 1) {
+        sig += 1;
+        if sig == 0 {
+            // Carried past bit 63; renormalize.
+            sig = 1 << 63;
+            exp += 1;
+        }
+    }
+
+    compose(sign, exp, sig)
+}
+
+fn div_impl(a: f80, b: f80) -> f80 {
+    if is_nan(a) {
+        return quiet_nan(a);
+    }
+    if is_nan(b) {
+        return quiet_nan(b);
+    }
+    let sign = a.sign() != b.sign();
+    let (a_inf, b_inf) = (is_inf(a), is_inf(b));
+    let (a_zero, b_zero) = (is_zero(a), is_zero(b));
+    if (a_inf && b_inf) || (a_zero && b_zero) {
+        return default_nan();
+    }
+    if a_inf || b_zero {
+        return signed_inf(sign);
+    }
+    if b_inf || a_zero {
+        return signed_zero(sign);
+    }
+
+    let (_, ea, ma) = decompose(a);
+    let (_, eb, mb) = decompose(b);
+
+    let (numerator, mut exp): (u128, i32) = if ma >= mb {
+        ((ma as u128) << 63, ea - eb)
+    } else {
+        ((ma as u128) << 64, ea - eb - 1)
+    };
+    let mb = mb as u128;
+    let mut q = numerator / mb;
+    let remainder = numerator % mb;
+
+    let twice_rem = remainder << 1;
+    let round_up = twice_rem > mb || (twice_rem == mb && (q & 1) == 1);
+    if round_up {
+        q += 1;
+        if q == 1 << 64 {
+            q = 1 << 63;
+            exp += 1;
+        }
+    }
+
+    compose(sign, exp, q as u64)
+}
+
+/// Extra low bits of precision kept while aligning/combining mantissas, so
+/// that cancellation during subtraction still rounds correctly.
+const EXTRA: u32 = 3;
+
+fn shift_right_sticky(v: u128, shift: u32) -> u128 {
+    if shift == 0 {
+        return v;
+    }
+    if shift >= 128 {
+        return (v != 0) as u128;
+    }
+    let sticky = (v & ((1u128 << shift) - 1)) != 0;
+    (v >> shift) | sticky as u128
+}
+
+fn add_sub_impl(a: f80, b_in: f80, sub: bool) -> f80 {
+    if is_nan(a) {
+        return quiet_nan(a);
+    }
+    if is_nan(b_in) {
+        return quiet_nan(b_in);
+    }
+
+    let b_sign = b_in.sign() != sub;
+    let (a_inf, b_inf) = (is_inf(a), is_inf(b_in));
+    if a_inf && b_inf {
+        return if a.sign() == b_sign {
+            signed_inf(a.sign())
+        } else {
+            default_nan()
+        };
+    }
+    if a_inf {
+        return signed_inf(a.sign());
+    }
+    if b_inf {
+        return signed_inf(b_sign);
+    }
+
+    let (a_zero, b_zero) = (is_zero(a), is_zero(b_in));
+    if a_zero && b_zero {
+        return signed_zero(a.sign() && b_sign);
+    }
+    if a_zero {
+        return if b_sign == b_in.sign() { b_in } else { -b_in };
+    }
+    if b_zero {
+        return a;
+    }
+
+    let (sa, ea, ma) = decompose(a);
+    let (_, eb, mb) = decompose(b_in);
+    let sb = b_sign;
+
+    // Order operands so `1` has the larger magnitude: this keeps the
+    // subtraction below non-negative and lets us renormalize by shifting
+    // left rather than handling a sign flip.
+    let (sign1, exp1, mant1, sign2, exp2, mant2) = if ea > eb || (ea == eb && ma >= mb) {
+        (sa, ea, ma, sb, eb, mb)
+    } else {
+        (sb, eb, mb, sa, ea, ma)
+    };
+
+    let diff = (exp1 - exp2) as u32;
+    let m1 = (mant1 as u128) << EXTRA;
+    let m2 = shift_right_sticky((mant2 as u128) << EXTRA, diff);
+
+    let (mut combined, mut exp) = if sign1 == sign2 {
+        (m1 + m2, exp1)
+    } else {
+        (m1 - m2, exp1) // m1 >= m2 by construction
+    };
+
+    if combined == 0 {
+        return signed_zero(false);
+    }
+
+    // Renormalize so the leading one sits at bit (63 + EXTRA).
+    let target_bit = 63 + EXTRA;
+    let pos = 127 - combined.leading_zeros() as i32;
+    let shift = pos - target_bit as i32;
+    if shift > 0 {
+        combined = shift_right_sticky(combined, shift as u32);
+        exp += shift;
+    } else if shift < 0 {
+        combined <<= -shift;
+        exp += shift;
+    }
+
+    let round_bit = (combined >> (EXTRA - 1)) & 1;
+    let sticky = (combined & ((1u128 << (EXTRA - 1)) - 1)) != 0;
+    let mut sig = (combined >> EXTRA) as u64;
+    if round_bit == 1 && (sticky || (sig & 1) == 1) {
+        sig += 1;
+        if sig == 0 {
+            sig = 1 << 63;
+            exp += 1;
+        }
+    }
+
+    compose(sign1, exp, sig)
+}
+
+impl f80 {
+    /// `self * a + b`, computed as two separately-rounded operations. This
+    /// does not give the extra precision of a true fused multiply-add, but
+    /// keeps the whole computation on the 80-bit significand rather than
+    /// routing through `f64`.
+    pub fn mul_add(self, a: f80, b: f80) -> f80 {
+        mul_impl(self, a) + b
+    }
+
+    /// Clears the sign bit.
+    pub fn abs(self) -> f80 {
+        f80::from_bits(self.to_bits() & !(1 << 79))
+    }
+}
+
+impl Add for f80 {
+    type Output = f80;
+    fn add(self, rhs: f80) -> f80 {
+        add_sub_impl(self, rhs, false)
+    }
+}
+impl Sub for f80 {
+    type Output = f80;
+    fn sub(self, rhs: f80) -> f80 {
+        add_sub_impl(self, rhs, true)
+    }
+}
+impl Mul for f80 {
+    type Output = f80;
+    fn mul(self, rhs: f80) -> f80 {
+        mul_impl(self, rhs)
+    }
+}
+impl Div for f80 {
+    type Output = f80;
+    fn div(self, rhs: f80) -> f80 {
+        div_impl(self, rhs)
+    }
+}
+impl Neg for f80 {
+    type Output = f80;
+    fn neg(self) -> f80 {
+        f80::from_bits(self.to_bits() ^ (1 << 79))
+    }
+}